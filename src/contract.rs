@@ -5,10 +5,15 @@ use bech32::{ToBase32, FromBase32};
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 
 use crate::error::ContractError;
 use crate::msg::{
-    Bech32AddrResponse, BytesAddrResponse, CountResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    BatchFromBech32Response, BatchResult, BatchToBech32Response, Bech32AddrResponse,
+    Bech32Variant, BytesAddrResponse, CountResponse, EthAddrResponse, EthBytesResponse,
+    ExecuteMsg, InstantiateMsg, QueryMsg,
 };
 use crate::state::{State, STATE};
 
@@ -70,11 +75,30 @@ pub fn try_reset(deps: DepsMut, info: MessageInfo, count: i32) -> Result<Respons
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
-        QueryMsg::GetCount {} => to_binary(&query_count(deps)?),
-        QueryMsg::ToBech32 { prefix, bytes } => to_binary(&to_bech32_addr(deps, prefix, bytes)?),
-        QueryMsg::FromBech32 { bech32 } => to_binary(&from_bech32_addr(deps, bech32)?),
+        QueryMsg::GetCount {} => Ok(to_binary(&query_count(deps)?)?),
+        QueryMsg::ToBech32 {
+            prefix,
+            bytes,
+            variant,
+        } => Ok(to_binary(&to_bech32_addr(deps, prefix, bytes, variant)?)?),
+        QueryMsg::FromBech32 { bech32 } => Ok(to_binary(&from_bech32_addr(deps, bech32)?)?),
+        QueryMsg::PubkeyToAddr { prefix, pubkey } => {
+            Ok(to_binary(&pubkey_to_addr(prefix, pubkey)?)?)
+        }
+        QueryMsg::PubkeyToEthAddr { pubkey } => Ok(to_binary(&pubkey_to_eth_addr(pubkey)?)?),
+        QueryMsg::EthAddrToBytes { eth_addr } => Ok(to_binary(&eth_addr_to_bytes(eth_addr)?)?),
+        QueryMsg::Reprefix {
+            address,
+            new_prefix,
+        } => Ok(to_binary(&reprefix_addr(deps, address, new_prefix)?)?),
+        QueryMsg::BatchFromBech32 { addresses } => {
+            Ok(to_binary(&batch_from_bech32(deps, addresses))?)
+        }
+        QueryMsg::BatchToBech32 { prefix, items } => {
+            Ok(to_binary(&batch_to_bech32(deps, prefix, items))?)
+        }
     }
 }
 
@@ -83,24 +107,151 @@ fn query_count(deps: Deps) -> StdResult<CountResponse> {
     Ok(CountResponse { count: state.count })
 }
 
-fn to_bech32_addr(_deps: Deps, prefix: String, bytes: [u8; 32]) -> StdResult<Bech32AddrResponse> {
-    let bech32_addr = bech32::encode(&prefix, bytes.to_vec().to_base32(), bech32::Variant::Bech32).unwrap();
-    Ok(Bech32AddrResponse{
-        bech32_addr,
+fn to_bech32_addr(
+    _deps: Deps,
+    prefix: String,
+    bytes: Binary,
+    variant: Option<Bech32Variant>,
+) -> Result<Bech32AddrResponse, ContractError> {
+    let variant: bech32::Variant = variant.unwrap_or(Bech32Variant::Bech32).into();
+    let bech32_addr = bech32::encode(&prefix, bytes.as_slice().to_base32(), variant)
+        .map_err(|e| ContractError::Bech32Encode(e.to_string()))?;
+    Ok(Bech32AddrResponse { bech32_addr })
+}
+
+fn from_bech32_addr(_deps: Deps, bech32_addr: String) -> Result<BytesAddrResponse, ContractError> {
+    let (prefix, data, variant) =
+        bech32::decode(&bech32_addr).map_err(|e| ContractError::Bech32Decode(e.to_string()))?;
+    // Both lengths are in bits: the 5-bit groups must pack into whole bytes.
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|_| ContractError::InvalidDataLength {
+        expected: data.len() * 5 / 8 * 8,
+        actual: data.len() * 5,
+    })?;
+
+    Ok(BytesAddrResponse {
+        prefix,
+        bytes: Binary::from(bytes),
+        variant: variant.into(),
     })
 }
 
-fn from_bech32_addr(_deps: Deps, bech32_addr: String) -> StdResult<BytesAddrResponse> {
-    let (prefix, data, _) = bech32::decode(&bech32_addr).unwrap();
-    let data = Vec::<u8>::from_base32(&data).unwrap();
+fn reprefix_addr(
+    _deps: Deps,
+    address: String,
+    new_prefix: String,
+) -> Result<Bech32AddrResponse, ContractError> {
+    let (_, data, variant) =
+        bech32::decode(&address).map_err(|e| ContractError::Bech32Decode(e.to_string()))?;
+    let bech32_addr = bech32::encode(&new_prefix, data, variant)
+        .map_err(|e| ContractError::Bech32Encode(e.to_string()))?;
+    Ok(Bech32AddrResponse { bech32_addr })
+}
 
-    let mut bytes = [0u8; 32];
-    bytes
-        .iter_mut()
-        .zip(&data)
-        .for_each(|(b1, b2)| *b1 = *b2);
+fn batch_from_bech32(deps: Deps, addresses: Vec<String>) -> BatchFromBech32Response {
+    let results = addresses
+        .into_iter()
+        .map(
+            |bech32_addr| match from_bech32_addr(deps, bech32_addr) {
+                Ok(resp) => BatchResult::Ok(resp),
+                Err(e) => BatchResult::Err(e.to_string()),
+            },
+        )
+        .collect();
+    BatchFromBech32Response { results }
+}
 
-    Ok(BytesAddrResponse { prefix, bytes })
+fn batch_to_bech32(deps: Deps, prefix: String, items: Vec<Binary>) -> BatchToBech32Response {
+    let results = items
+        .into_iter()
+        .map(
+            |bytes| match to_bech32_addr(deps, prefix.clone(), bytes, None) {
+                Ok(resp) => BatchResult::Ok(resp),
+                Err(e) => BatchResult::Err(e.to_string()),
+            },
+        )
+        .collect();
+    BatchToBech32Response { results }
+}
+
+fn pubkey_to_addr(prefix: String, pubkey: Binary) -> Result<Bech32AddrResponse, ContractError> {
+    if pubkey.len() != 33 {
+        return Err(ContractError::InvalidPubkeyLength {
+            expected: 33,
+            actual: pubkey.len(),
+        });
+    }
+
+    let sha256_hash = Sha256::digest(pubkey.as_slice());
+    let account_id = Ripemd160::digest(sha256_hash);
+
+    let bech32_addr = bech32::encode(
+        &prefix,
+        account_id.as_slice().to_base32(),
+        bech32::Variant::Bech32,
+    )
+    .map_err(|e| ContractError::Bech32Encode(e.to_string()))?;
+    Ok(Bech32AddrResponse { bech32_addr })
+}
+
+fn pubkey_to_eth_addr(pubkey: Binary) -> Result<EthAddrResponse, ContractError> {
+    if pubkey.len() != 65 {
+        return Err(ContractError::InvalidPubkeyLength {
+            expected: 65,
+            actual: pubkey.len(),
+        });
+    }
+    if pubkey[0] != 0x04 {
+        return Err(ContractError::InvalidEthAddress(
+            "uncompressed pubkey must start with 0x04".to_string(),
+        ));
+    }
+
+    let hash = Keccak256::digest(&pubkey[1..]);
+    let raw_addr = &hash[12..];
+
+    Ok(EthAddrResponse {
+        eth_addr: eip55_checksum(raw_addr),
+    })
+}
+
+// EIP-55: lowercase-hex-encode the address, keccak256 the ASCII hex string,
+// then uppercase each hex digit whose corresponding hash nibble is >= 8.
+fn eip55_checksum(raw_addr: &[u8]) -> String {
+    let lower_hex = hex::encode(raw_addr);
+    let hash = Keccak256::digest(lower_hex.as_bytes());
+
+    let checksummed: String = lower_hex
+        .char_indices()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    format!("0x{}", checksummed)
+}
+
+fn eth_addr_to_bytes(eth_addr: String) -> Result<EthBytesResponse, ContractError> {
+    let hex_part = eth_addr
+        .strip_prefix("0x")
+        .ok_or_else(|| ContractError::InvalidEthAddress(eth_addr.clone()))?;
+    if hex_part.len() != 40 {
+        return Err(ContractError::InvalidEthAddress(eth_addr));
+    }
+    let bytes =
+        hex::decode(hex_part).map_err(|_| ContractError::InvalidEthAddress(eth_addr.clone()))?;
+
+    Ok(EthBytesResponse {
+        bytes: Binary::from(bytes),
+    })
 }
 
 #[cfg(test)]
@@ -184,20 +335,249 @@ mod tests {
         // Mock data(obtained from "fromBech32" & "toBech32" in `cosmjs/encoding` npm pkg)
         let mock_beck32_addr = "juno1lqgdq9u8zhcvwwwz3xjswactrtq6qzptmlzlh6xspl34dxq32uhqhlphat";
         let mock_prefix = "juno".to_string();
-        let mock_bytes: [u8; 32] = [
+        let mock_bytes: Binary = Binary::from(vec![
             248, 16, 208, 23, 135, 21, 240, 199, 57, 194, 137, 165, 7, 119, 11, 26, 193, 160, 8,
             43, 223, 197, 251, 232, 208, 15, 227, 86, 152, 17, 87, 46,
-        ];
+        ]);
 
         // Check "FromBech32"
         let res = query(deps.as_ref(), mock_env(), QueryMsg::FromBech32 { bech32: mock_beck32_addr.to_string() }).unwrap();
         let bytes_addr_resp: BytesAddrResponse = from_binary(&res).unwrap();
         assert_eq!(bytes_addr_resp.prefix, mock_prefix.clone());
         assert_eq!(bytes_addr_resp.bytes, mock_bytes);
+        assert_eq!(bytes_addr_resp.variant, Bech32Variant::Bech32);
 
         // Check "ToBech32"
-        let res = query(deps.as_ref(), mock_env(), QueryMsg::ToBech32 { prefix: mock_prefix, bytes: mock_bytes }).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ToBech32 {
+                prefix: mock_prefix,
+                bytes: mock_bytes,
+                variant: None,
+            },
+        )
+        .unwrap();
         let bech32_addr_resp: Bech32AddrResponse = from_binary(&res).unwrap();
         assert_eq!(bech32_addr_resp.bech32_addr, mock_beck32_addr.to_string());
     }
+
+    #[test]
+    fn addr_conversion_roundtrips_20_byte_and_bech32m() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // 20-byte secp256k1 account address, round-tripped under Bech32m.
+        let mock_bytes: Binary = Binary::from(vec![
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ]);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ToBech32 {
+                prefix: "osmo".to_string(),
+                bytes: mock_bytes.clone(),
+                variant: Some(Bech32Variant::Bech32m),
+            },
+        )
+        .unwrap();
+        let bech32_addr_resp: Bech32AddrResponse = from_binary(&res).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FromBech32 {
+                bech32: bech32_addr_resp.bech32_addr,
+            },
+        )
+        .unwrap();
+        let bytes_addr_resp: BytesAddrResponse = from_binary(&res).unwrap();
+        assert_eq!(bytes_addr_resp.prefix, "osmo".to_string());
+        assert_eq!(bytes_addr_resp.bytes, mock_bytes);
+        assert_eq!(bytes_addr_resp.variant, Bech32Variant::Bech32m);
+    }
+
+    #[test]
+    fn pubkey_to_addr_rejects_wrong_length() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // A compressed secp256k1 pubkey must be exactly 33 bytes.
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PubkeyToAddr {
+                prefix: "cosmos".to_string(),
+                pubkey: Binary::from(vec![0u8; 32]),
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn eth_addr_roundtrips_through_bytes() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let eth_addr = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::EthAddrToBytes {
+                eth_addr: eth_addr.clone(),
+            },
+        )
+        .unwrap();
+        let bytes_resp: EthBytesResponse = from_binary(&res).unwrap();
+        assert_eq!(bytes_resp.bytes.len(), 20);
+        assert_eq!(eip55_checksum(bytes_resp.bytes.as_slice()), eth_addr);
+    }
+
+    #[test]
+    fn pubkey_to_eth_addr_rejects_wrong_length() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // Uncompressed secp256k1 pubkeys must be 65 bytes and start with 0x04.
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PubkeyToEthAddr {
+                pubkey: Binary::from(vec![0u8; 33]),
+            },
+        );
+        match res {
+            Err(ContractError::InvalidPubkeyLength { .. }) => {}
+            _ => panic!("Must return an InvalidPubkeyLength error"),
+        }
+    }
+
+    #[test]
+    fn pubkey_to_eth_addr_rejects_bad_prefix_byte() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // Right length (65 bytes), wrong leading byte — this is a distinct
+        // failure from a length mismatch.
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PubkeyToEthAddr {
+                pubkey: Binary::from(vec![0u8; 65]),
+            },
+        );
+        match res {
+            Err(ContractError::InvalidEthAddress(_)) => {}
+            _ => panic!("Must return an InvalidEthAddress error"),
+        }
+    }
+
+    #[test]
+    fn reprefix_rewrites_hrp_only() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let mock_beck32_addr = "juno1lqgdq9u8zhcvwwwz3xjswactrtq6qzptmlzlh6xspl34dxq32uhqhlphat";
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Reprefix {
+                address: mock_beck32_addr.to_string(),
+                new_prefix: "osmo".to_string(),
+            },
+        )
+        .unwrap();
+        let reprefixed: Bech32AddrResponse = from_binary(&res).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FromBech32 {
+                bech32: reprefixed.bech32_addr,
+            },
+        )
+        .unwrap();
+        let original = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FromBech32 {
+                bech32: mock_beck32_addr.to_string(),
+            },
+        )
+        .unwrap();
+        let bytes_addr_resp: BytesAddrResponse = from_binary(&res).unwrap();
+        let original_resp: BytesAddrResponse = from_binary(&original).unwrap();
+        assert_eq!(bytes_addr_resp.prefix, "osmo".to_string());
+        assert_eq!(bytes_addr_resp.bytes, original_resp.bytes);
+        assert_eq!(bytes_addr_resp.variant, original_resp.variant);
+    }
+
+    #[test]
+    fn from_bech32_rejects_malformed_input() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FromBech32 {
+                bech32: "not-a-bech32-address".to_string(),
+            },
+        );
+        match res {
+            Err(ContractError::Bech32Decode(_)) => {}
+            _ => panic!("Must return a Bech32Decode error"),
+        }
+    }
+
+    #[test]
+    fn batch_from_bech32_reports_per_item_errors() {
+        let mut deps = mock_dependencies_with_balance(&coins(2, "token"));
+
+        let msg = InstantiateMsg { count: 17 };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let mock_beck32_addr = "juno1lqgdq9u8zhcvwwwz3xjswactrtq6qzptmlzlh6xspl34dxq32uhqhlphat";
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::BatchFromBech32 {
+                addresses: vec![
+                    mock_beck32_addr.to_string(),
+                    "not-a-bech32-address".to_string(),
+                ],
+            },
+        )
+        .unwrap();
+        let batch_resp: BatchFromBech32Response = from_binary(&res).unwrap();
+        assert_eq!(batch_resp.results.len(), 2);
+        assert!(matches!(batch_resp.results[0], BatchResult::Ok(_)));
+        assert!(matches!(batch_resp.results[1], BatchResult::Err(_)));
+    }
 }
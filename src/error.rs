@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid pubkey length: expected {expected}, got {actual}")]
+    InvalidPubkeyLength { expected: usize, actual: usize },
+
+    #[error("Invalid Ethereum address: {0}")]
+    InvalidEthAddress(String),
+
+    #[error("Failed to decode bech32 address: {0}")]
+    Bech32Decode(String),
+
+    #[error("Failed to encode bech32 address: {0}")]
+    Bech32Encode(String),
+
+    #[error("Invalid data length: expected {expected}, got {actual}")]
+    InvalidDataLength { expected: usize, actual: usize },
+}
@@ -1,6 +1,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use cosmwasm_std::Binary;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub count: i32,
@@ -13,17 +15,91 @@ pub enum ExecuteMsg {
     Reset { count: i32 },
 }
 
+// Mirrors `bech32::Variant`, which isn't (de)serializable, so queries can
+// request/report the checksum variant used to encode an address.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Bech32Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl From<bech32::Variant> for Bech32Variant {
+    fn from(variant: bech32::Variant) -> Self {
+        match variant {
+            bech32::Variant::Bech32 => Bech32Variant::Bech32,
+            bech32::Variant::Bech32m => Bech32Variant::Bech32m,
+        }
+    }
+}
+
+impl From<Bech32Variant> for bech32::Variant {
+    fn from(variant: Bech32Variant) -> Self {
+        match variant {
+            Bech32Variant::Bech32 => bech32::Variant::Bech32,
+            Bech32Variant::Bech32m => bech32::Variant::Bech32m,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     // GetCount returns the current count as a json-encoded number
     GetCount {},
 
-    // Convert the bytes array([u8; 32]) to bech32 address
-    ToBech32 { prefix: String, bytes: [u8; 32] },
+    // Convert a variable-length byte array to a bech32 address. Defaults to
+    // the Bech32 checksum variant when `variant` is omitted.
+    ToBech32 {
+        prefix: String,
+        bytes: Binary,
+        variant: Option<Bech32Variant>,
+    },
 
-    // Convert the bech32 address to `prefix` & `bytes array`
+    // Convert the bech32 address to `prefix`, `bytes` & checksum `variant`
     FromBech32 { bech32: String },
+
+    // Derive the bech32 account address for a compressed secp256k1 pubkey,
+    // the standard Cosmos way: bech32(ripemd160(sha256(pubkey)), prefix)
+    PubkeyToAddr { prefix: String, pubkey: Binary },
+
+    // Derive the EIP-55 checksummed `0x` Ethereum address for an
+    // uncompressed secp256k1 pubkey
+    PubkeyToEthAddr { pubkey: Binary },
+
+    // Parse a `0x`-prefixed, 40 hex char Ethereum address into raw bytes
+    EthAddrToBytes { eth_addr: String },
+
+    // Decode a bech32 address and re-encode its data bytes under a
+    // different HRP, preserving the checksum variant
+    Reprefix { address: String, new_prefix: String },
+
+    // Convert many bech32 addresses in a single query; a malformed entry
+    // doesn't fail the whole batch
+    BatchFromBech32 { addresses: Vec<String> },
+
+    // Convert many byte arrays to bech32 addresses under the same prefix in
+    // a single query; a malformed entry doesn't fail the whole batch
+    BatchToBech32 { prefix: String, items: Vec<Binary> },
+}
+
+// Per-item outcome of a batch conversion: `Ok(T)` on success, `Err(message)`
+// on failure, so one bad entry doesn't fail the whole batch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchResult<T> {
+    Ok(T),
+    Err(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchFromBech32Response {
+    pub results: Vec<BatchResult<BytesAddrResponse>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchToBech32Response {
+    pub results: Vec<BatchResult<Bech32AddrResponse>>,
 }
 
 // We define a custom struct for each query response
@@ -40,5 +116,16 @@ pub struct Bech32AddrResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BytesAddrResponse {
     pub prefix: String,
-    pub bytes: [u8; 32],
+    pub bytes: Binary,
+    pub variant: Bech32Variant,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EthAddrResponse {
+    pub eth_addr: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EthBytesResponse {
+    pub bytes: Binary,
 }